@@ -22,54 +22,67 @@ use crate::http::core::*;
 use crate::http::*;
 use crate::util::path::ContextDir;
 
+/// The shell flavor used to quote the arguments of an exported curl command.
+///
+/// The quoting rules of POSIX shells, Windows PowerShell and `cmd.exe` are
+/// incompatible with each other, so a command that is correct for one of them
+/// is usually broken for the others. This enum lets `curl_args` emit arguments
+/// quoted for the shell the user will paste them into.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ShellFlavor {
+    /// POSIX compatible shells (`sh`, `bash`, `zsh`, ...).
+    #[default]
+    Posix,
+    /// Windows PowerShell.
+    PowerShell,
+    /// Windows `cmd.exe`.
+    Cmd,
+}
+
 impl RequestSpec {
-    /// Returns this request as curl arguments.
+    /// Returns this request as curl arguments, quoted for the POSIX shell.
     /// It does not contain the requests cookies (they will be accessed from the client)
     pub fn curl_args(&self, context_dir: &ContextDir) -> Vec<String> {
+        self.curl_args_for(context_dir, ShellFlavor::Posix)
+    }
+
+    /// Returns this request as curl arguments, quoted for the given shell `flavor`.
+    /// It does not contain the requests cookies (they will be accessed from the client)
+    pub fn curl_args_for(&self, context_dir: &ContextDir, flavor: ShellFlavor) -> Vec<String> {
         let mut arguments = vec![];
 
         let data =
             !self.multipart.is_empty() || !self.form.is_empty() || !self.body.bytes().is_empty();
         arguments.append(&mut self.method.curl_args(data));
+        arguments.append(&mut self.transport_curl_args());
 
         for header in self.headers.iter() {
-            arguments.append(&mut header.curl_args());
+            arguments.append(&mut header.curl_args(flavor));
         }
 
         let has_explicit_content_type = self.headers.contains_key(CONTENT_TYPE);
         if !has_explicit_content_type {
-            if let Some(content_type) = &self.implicit_content_type {
-                if content_type != "application/x-www-form-urlencoded"
-                    && content_type != "multipart/form-data"
-                {
-                    arguments.push("--header".to_string());
-                    arguments.push(format!("'{}: {content_type}'", CONTENT_TYPE));
-                }
-            } else if !self.body.bytes().is_empty() {
-                match self.body {
-                    Body::Text(_) => {
-                        arguments.push("--header".to_string());
-                        arguments.push(format!("'{}:'", CONTENT_TYPE));
-                    }
-                    Body::Binary(_) => {
-                        arguments.push("--header".to_string());
-                        arguments.push(format!("'{}: application/octet-stream'", CONTENT_TYPE));
-                    }
-                    Body::File(_, _) => {
-                        arguments.push("--header".to_string());
-                        arguments.push(format!("'{}:'", CONTENT_TYPE));
-                    }
-                }
+            if let Some(content_type) = self.implicit_content_type_value() {
+                arguments.push("--header".to_string());
+                arguments.push(encode_shell_string(
+                    &format!("{CONTENT_TYPE}: {content_type}"),
+                    flavor,
+                ));
+            } else if self.implicit_content_type.is_none() && !self.body.bytes().is_empty() {
+                // Text and file bodies: curl would guess a content type, so we add an
+                // empty header to prevent it (binary bodies are handled above).
+                arguments.push("--header".to_string());
+                arguments.push(encode_shell_string(&format!("{CONTENT_TYPE}:"), flavor));
             }
         }
 
         for param in self.form.iter() {
             arguments.push("--data".to_string());
-            arguments.push(format!("'{}'", param.curl_arg_escape()));
+            arguments.push(encode_shell_string(&param.curl_arg_form_escape(), flavor));
         }
         for param in self.multipart.iter() {
             arguments.push("--form".to_string());
-            arguments.push(format!("'{}'", param.curl_arg(context_dir)));
+            arguments.push(encode_shell_string(&param.curl_arg(context_dir), flavor));
         }
 
         if !self.body.bytes().is_empty() {
@@ -95,32 +108,288 @@ impl RequestSpec {
                 _ => "--data",
             };
             arguments.push(param.to_string());
-            arguments.push(self.body.curl_arg(context_dir));
+            arguments.push(self.body.curl_arg(context_dir, flavor));
+        }
+
+        arguments.push(encode_shell_string(&self.url_with_querystring(), flavor));
+
+        arguments
+    }
+
+    /// Returns the request URL with `self.querystring` folded in, exactly as
+    /// [`RequestSpec::curl_args`] builds the final URL: each param is
+    /// form-escaped, joined with `&`, and appended with `?` or `&` depending on
+    /// whether the raw URL already carries a query.
+    fn url_with_querystring(&self) -> String {
+        if self.querystring.is_empty() {
+            return self.url.raw();
+        }
+        let params = self
+            .querystring
+            .iter()
+            .map(|p| p.curl_arg_form_escape())
+            .collect::<Vec<String>>()
+            .join("&");
+        if self.url.raw().contains('?') {
+            format!("{}&{params}", self.url.raw())
+        } else {
+            format!("{}?{params}", self.url.raw())
+        }
+    }
+
+    /// Returns the curl flags describing the protocol and transport options this
+    /// request was run with: the forced HTTP version (`--http1.0` / `--http1.1` /
+    /// `--http2` / `--http3`), `--compressed` when a compressed response is expected, and the
+    /// minimum TLS version (`--tlsv1.2` / `--tlsv1.3`).
+    fn transport_curl_args(&self) -> Vec<String> {
+        let mut arguments = vec![];
+        match self.http_version {
+            RequestedHttpVersion::Default => {}
+            RequestedHttpVersion::Http10 => arguments.push("--http1.0".to_string()),
+            RequestedHttpVersion::Http11 => arguments.push("--http1.1".to_string()),
+            RequestedHttpVersion::Http2 => arguments.push("--http2".to_string()),
+            RequestedHttpVersion::Http3 => arguments.push("--http3".to_string()),
+        }
+        if self.compressed {
+            arguments.push("--compressed".to_string());
+        }
+        match self.ssl_min_version {
+            None => {}
+            Some(SslVersion::Tls1_2) => arguments.push("--tlsv1.2".to_string()),
+            Some(SslVersion::Tls1_3) => arguments.push("--tlsv1.3".to_string()),
         }
+        arguments
+    }
 
-        let querystring = if self.querystring.is_empty() {
-            String::new()
+    /// Returns the `Content-Type` value Hurl sets implicitly when the request
+    /// has no explicit one, or `None` when the content type is left to the body
+    /// encoding (form and multipart bodies, or no body at all). The caller is
+    /// responsible for checking that no explicit `Content-Type` header is set.
+    fn implicit_content_type_value(&self) -> Option<String> {
+        if let Some(content_type) = &self.implicit_content_type {
+            if content_type != "application/x-www-form-urlencoded"
+                && content_type != "multipart/form-data"
+            {
+                Some(content_type.clone())
+            } else {
+                None
+            }
+        } else if matches!(self.body, Body::Binary(_)) && !self.body.bytes().is_empty() {
+            Some("application/octet-stream".to_string())
         } else {
+            None
+        }
+    }
+
+    /// Renders this request as a raw HTTP/1.1 message: a request line, a `Host`
+    /// header derived from the URL when absent, the explicit headers, the same
+    /// implicit content-type inference as [`RequestSpec::curl_args`], a blank
+    /// line, then the serialized body (form params urlencoded, multipart
+    /// assembled with a generated boundary, or file contents resolved through
+    /// `context_dir`).
+    ///
+    /// The result is a paste-ready `.http` request that can be fed to netcat or
+    /// a proxy, or stored as a fixture.
+    pub fn http_raw(&self, context_dir: &ContextDir) -> Vec<u8> {
+        let raw = self.url_with_querystring();
+        let url = url::Url::parse(&raw);
+        let target = match &url {
+            Ok(u) => match u.query() {
+                Some(query) => format!("{}?{}", u.path(), query),
+                None => u.path().to_string(),
+            },
+            Err(_) => raw.clone(),
+        };
+
+        let mut out = vec![];
+        out.extend_from_slice(format!("{} {target} HTTP/1.1\r\n", self.method.0).as_bytes());
+
+        if !self.headers.contains_key("Host") {
+            if let Ok(u) = &url {
+                if let Some(host) = u.host_str() {
+                    let authority = match u.port() {
+                        Some(port) => format!("{host}:{port}"),
+                        None => host.to_string(),
+                    };
+                    out.extend_from_slice(format!("Host: {authority}\r\n").as_bytes());
+                }
+            }
+        }
+
+        for header in self.headers.iter() {
+            out.extend_from_slice(format!("{}: {}\r\n", header.name, header.value).as_bytes());
+        }
+
+        if !self.headers.contains_key(CONTENT_TYPE) {
+            let content_type = if !self.form.is_empty() {
+                Some("application/x-www-form-urlencoded".to_string())
+            } else if !self.multipart.is_empty() {
+                Some(format!("multipart/form-data; boundary={MULTIPART_BOUNDARY}"))
+            } else {
+                self.implicit_content_type_value()
+            };
+            if let Some(content_type) = content_type {
+                out.extend_from_slice(format!("{CONTENT_TYPE}: {content_type}\r\n").as_bytes());
+            }
+        }
+
+        let body = self.body_raw(context_dir);
+        // Most servers won't read an HTTP/1.1 body without a length, so derive
+        // Content-Length from the serialized body unless the request already
+        // carries an explicit length or chunked transfer-encoding.
+        let has_length = self.headers.contains_key("Content-Length")
+            || self.headers.contains_key("Transfer-Encoding");
+        if !body.is_empty() && !has_length {
+            out.extend_from_slice(format!("Content-Length: {}\r\n", body.len()).as_bytes());
+        }
+
+        out.extend_from_slice(b"\r\n");
+        out.extend(body);
+        out
+    }
+
+    /// Serializes the body for [`RequestSpec::http_raw`]: form params as an
+    /// urlencoded string, multipart parts assembled with [`MULTIPART_BOUNDARY`],
+    /// otherwise the raw body bytes.
+    fn body_raw(&self, context_dir: &ContextDir) -> Vec<u8> {
+        if !self.form.is_empty() {
             let params = self
-                .querystring
+                .form
                 .iter()
-                .map(|p| p.curl_arg_escape())
+                .map(|p| p.curl_arg_form_escape())
                 .collect::<Vec<String>>();
-            params.join("&")
-        };
-        let url = if querystring.as_str() == "" {
-            self.url.raw()
-        } else if self.url.raw().contains('?') {
-            format!("{}&{}", self.url.raw(), querystring)
+            params.join("&").into_bytes()
+        } else if !self.multipart.is_empty() {
+            let mut out = vec![];
+            for param in self.multipart.iter() {
+                out.extend_from_slice(format!("--{MULTIPART_BOUNDARY}\r\n").as_bytes());
+                match param {
+                    MultipartParam::Param(Param { name, value }) => {
+                        out.extend_from_slice(
+                            format!("Content-Disposition: form-data; name=\"{name}\"\r\n\r\n")
+                                .as_bytes(),
+                        );
+                        out.extend_from_slice(value.as_bytes());
+                    }
+                    MultipartParam::FileParam(FileParam {
+                        name,
+                        filename,
+                        content_type,
+                        ..
+                    }) => {
+                        out.extend_from_slice(
+                            format!(
+                                "Content-Disposition: form-data; name=\"{name}\"; filename=\"{filename}\"\r\n"
+                            )
+                            .as_bytes(),
+                        );
+                        out.extend_from_slice(
+                            format!("Content-Type: {content_type}\r\n\r\n").as_bytes(),
+                        );
+                        let path = context_dir.resolved_path(Path::new(filename));
+                        if let Ok(bytes) = std::fs::read(path) {
+                            out.extend_from_slice(&bytes);
+                        }
+                    }
+                }
+                out.extend_from_slice(b"\r\n");
+            }
+            out.extend_from_slice(format!("--{MULTIPART_BOUNDARY}--\r\n").as_bytes());
+            out
         } else {
-            format!("{}?{}", self.url.raw(), querystring)
+            self.body.bytes().to_vec()
+        }
+    }
+}
+
+/// Boundary used when assembling a multipart body for [`RequestSpec::http_raw`].
+const MULTIPART_BOUNDARY: &str = "------------------------boundary";
+
+impl RequestSpec {
+    /// Encodes this request in the known-length Binary HTTP format (RFC 9292).
+    ///
+    /// The layout is a framing indicator of `0` (known-length request), the
+    /// request control data as four length-prefixed byte strings (method,
+    /// scheme, authority, path-and-query), the known-length field section built
+    /// from `self.headers`, the content from `self.body`, and an empty trailer
+    /// section. All integers use the QUIC variable-length encoding (RFC 9000).
+    /// Useful for OHTTP gateways and binary logging.
+    pub fn to_binary_http(&self) -> Vec<u8> {
+        let url = url::Url::parse(&self.url_with_querystring());
+        let scheme = url.as_ref().map(|u| u.scheme().to_string()).unwrap_or_default();
+        let authority = match &url {
+            Ok(u) => {
+                let host = u.host_str().unwrap_or_default();
+                match u.port() {
+                    Some(port) => format!("{host}:{port}"),
+                    None => host.to_string(),
+                }
+            }
+            Err(_) => String::new(),
+        };
+        let target = match &url {
+            Ok(u) => match u.query() {
+                Some(query) => format!("{}?{}", u.path(), query),
+                None => u.path().to_string(),
+            },
+            Err(_) => String::new(),
         };
-        arguments.push(format!("'{url}'"));
 
-        arguments
+        let mut buf = vec![];
+        // Framing indicator: 0 = known-length request.
+        encode_varint(0, &mut buf);
+
+        // Request control data.
+        encode_field(self.method.0.as_bytes(), &mut buf);
+        encode_field(scheme.as_bytes(), &mut buf);
+        encode_field(authority.as_bytes(), &mut buf);
+        encode_field(target.as_bytes(), &mut buf);
+
+        // Known-length field section: a varint byte length followed by the
+        // (name, value) pairs. Pseudo-header fields live in the control data and
+        // are not duplicated here.
+        let mut fields = vec![];
+        for header in self.headers.iter() {
+            if header.name.starts_with(':') {
+                continue;
+            }
+            encode_field(header.name.as_bytes(), &mut fields);
+            encode_field(header.value.as_bytes(), &mut fields);
+        }
+        encode_varint(fields.len() as u64, &mut buf);
+        buf.extend_from_slice(&fields);
+
+        // Content (zero-length when there is no body).
+        encode_field(self.body.bytes(), &mut buf);
+
+        // Empty trailer section.
+        encode_varint(0, &mut buf);
+
+        buf
     }
 }
 
+/// Appends `value` to `buf` using the QUIC variable-length integer encoding
+/// (RFC 9000, section 16): the two most-significant bits of the first byte
+/// select a 1, 2, 4 or 8 byte big-endian representation.
+fn encode_varint(value: u64, buf: &mut Vec<u8>) {
+    if value <= 63 {
+        buf.push(value as u8);
+    } else if value <= 16383 {
+        buf.extend_from_slice(&(value as u16 | 0x4000).to_be_bytes());
+    } else if value <= 1_073_741_823 {
+        buf.extend_from_slice(&(value as u32 | 0x8000_0000).to_be_bytes());
+    } else {
+        buf.extend_from_slice(&(value | 0xc000_0000_0000_0000).to_be_bytes());
+    }
+}
+
+/// Appends a varint-length-prefixed byte string to `buf`.
+fn encode_field(bytes: &[u8], buf: &mut Vec<u8>) {
+    encode_varint(bytes.len() as u64, buf);
+    buf.extend_from_slice(bytes);
+}
+
 fn encode_byte(b: u8) -> String {
     format!("\\x{b:02x}")
 }
@@ -153,12 +422,12 @@ impl Method {
 }
 
 impl Header {
-    pub fn curl_args(&self) -> Vec<String> {
+    pub fn curl_args(&self, flavor: ShellFlavor) -> Vec<String> {
         let name = &self.name;
         let value = &self.value;
         vec![
             "--header".to_string(),
-            encode_shell_string(&format!("{name}: {value}")),
+            encode_shell_string(&format!("{name}: {value}"), flavor),
         ]
     }
 }
@@ -175,6 +444,16 @@ impl Param {
         let value = &self.value;
         format!("{name}={value}")
     }
+
+    /// Encodes this param as `name=value` using the `application/x-www-form-urlencoded`
+    /// serializer of the URL Standard (space → `+`, `*-._` preserved, everything
+    /// else percent-encoded), so the generated command round-trips to what Hurl
+    /// actually sent for form bodies and query strings.
+    pub fn curl_arg_form_escape(&self) -> String {
+        let name = escape_form(&self.name);
+        let value = escape_form(&self.value);
+        format!("{name}={value}")
+    }
 }
 
 impl MultipartParam {
@@ -196,13 +475,13 @@ impl MultipartParam {
 }
 
 impl Body {
-    pub fn curl_arg(&self, context_dir: &ContextDir) -> String {
+    pub fn curl_arg(&self, context_dir: &ContextDir, flavor: ShellFlavor) -> String {
         match self {
-            Body::Text(s) => encode_shell_string(s),
-            Body::Binary(bytes) => format!("$'{}'", encode_bytes(bytes)),
+            Body::Text(s) => encode_shell_string(s, flavor),
+            Body::Binary(bytes) => encode_shell_bytes(bytes, flavor),
             Body::File(_, filename) => {
                 let path = context_dir.resolved_path(Path::new(filename));
-                format!("'@{}'", path.to_string_lossy())
+                encode_shell_string(&format!("@{}", path.to_string_lossy()), flavor)
             }
         }
     }
@@ -212,7 +491,48 @@ fn escape_url(s: &str) -> String {
     percent_encoding::percent_encode(s.as_bytes(), percent_encoding::NON_ALPHANUMERIC).to_string()
 }
 
-fn encode_shell_string(s: &str) -> String {
+/// Encodes `s` following the `application/x-www-form-urlencoded` serializer of
+/// the URL Standard: a space becomes `+`, `*`, `-`, `.`, `_` and alphanumerics
+/// are kept as-is, and every other byte is percent-encoded. Unlike
+/// [`escape_url`], this matches what a browser (and Hurl) sends for form bodies
+/// and query strings.
+fn escape_form(s: &str) -> String {
+    let mut encoded = String::new();
+    for b in s.bytes() {
+        match b {
+            b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' | b'*' | b'-' | b'.' | b'_' => {
+                encoded.push(b as char);
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{b:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Quotes `s` as a single argument for the given shell `flavor`.
+fn encode_shell_string(s: &str, flavor: ShellFlavor) -> String {
+    match flavor {
+        ShellFlavor::Posix => encode_posix_string(s),
+        ShellFlavor::PowerShell => encode_powershell_string(s),
+        ShellFlavor::Cmd => encode_cmd_string(s),
+    }
+}
+
+/// Quotes raw `bytes` as a single argument for the given shell `flavor`.
+///
+/// Only POSIX shells can represent arbitrary bytes faithfully (through the
+/// `$'...'` ANSI-C form); PowerShell and `cmd.exe` fall back to a double-quoted
+/// `\xNN` rendering that keeps the bytes visible even though they are not
+/// interpreted.
+fn encode_shell_bytes(bytes: &[u8], flavor: ShellFlavor) -> String {
+    match flavor {
+        ShellFlavor::Posix => format!("$'{}'", encode_bytes(bytes)),
+        ShellFlavor::PowerShell | ShellFlavor::Cmd => format!("\"{}\"", encode_bytes(bytes)),
+    }
+}
+
+fn encode_posix_string(s: &str) -> String {
     // $'...' form will be used to encode escaped sequence
     if escape_mode(s) {
         let escaped = escape_string(s);
@@ -250,6 +570,44 @@ fn escape_string(s: &str) -> String {
     escaped
 }
 
+// PowerShell double-quoted strings use the backtick as escape character; `n and
+// `t stand for newline and tab.
+fn encode_powershell_string(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        match c {
+            '\n' => escaped.push_str("`n"),
+            '\t' => escaped.push_str("`t"),
+            '`' => escaped.push_str("``"),
+            '"' => escaped.push_str("`\""),
+            '$' => escaped.push_str("`$"),
+            _ => escaped.push(c),
+        }
+    }
+    format!("\"{escaped}\"")
+}
+
+// Inside a double-quoted string cmd.exe already treats `& | < > ^` as literal,
+// so they are emitted verbatim — caret-escaping them would inject a stray caret
+// into the value. Only an embedded double quote needs escaping (as `\"`, which
+// curl's own argument parser unescapes), and `%` is doubled to suppress `%VAR%`
+// expansion. cmd has no way to embed a literal newline or tab on a single line,
+// so those are rendered as the visible `\n` / `\t` escapes to keep the command
+// on one line rather than silently breaking it.
+fn encode_cmd_string(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '%' => escaped.push_str("%%"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(c),
+        }
+    }
+    format!("\"{escaped}\"")
+}
+
 #[cfg(test)]
 pub mod tests {
     use std::path::Path;
@@ -302,6 +660,43 @@ pub mod tests {
         assert_eq!(encode_byte(32), "\\x20".to_string());
     }
 
+    #[test]
+    fn test_encode_varint() {
+        let mut buf = vec![];
+        encode_varint(0, &mut buf);
+        assert_eq!(buf, vec![0x00]);
+
+        let mut buf = vec![];
+        encode_varint(37, &mut buf);
+        assert_eq!(buf, vec![0x25]);
+
+        let mut buf = vec![];
+        encode_varint(15293, &mut buf);
+        assert_eq!(buf, vec![0x7b, 0xbd]);
+
+        let mut buf = vec![];
+        encode_varint(494_878_333, &mut buf);
+        assert_eq!(buf, vec![0x9d, 0x7f, 0x3e, 0x7d]);
+    }
+
+    #[test]
+    fn test_to_binary_http() {
+        let mut expected = vec![];
+        expected.push(0x00); // framing indicator: known-length request
+        expected.extend_from_slice(&[0x03]);
+        expected.extend_from_slice(b"GET");
+        expected.extend_from_slice(&[0x04]);
+        expected.extend_from_slice(b"http");
+        expected.extend_from_slice(&[0x0e]);
+        expected.extend_from_slice(b"localhost:8000");
+        expected.extend_from_slice(&[0x06]);
+        expected.extend_from_slice(b"/hello");
+        expected.push(0x00); // empty field section
+        expected.push(0x00); // empty content
+        expected.push(0x00); // empty trailer section
+        assert_eq!(hello_http_request().to_binary_http(), expected);
+    }
+
     #[test]
     fn method_curl_args() {
         assert!(Method("GET".to_string()).curl_args(false).is_empty());
@@ -329,16 +724,30 @@ pub mod tests {
     #[test]
     fn header_curl_args() {
         assert_eq!(
-            Header::new("Host", "example.com").curl_args(),
+            Header::new("Host", "example.com").curl_args(ShellFlavor::Posix),
             vec!["--header".to_string(), "'Host: example.com'".to_string()]
         );
         assert_eq!(
-            Header::new("If-Match", "\"e0023aa4e\"").curl_args(),
+            Header::new("If-Match", "\"e0023aa4e\"").curl_args(ShellFlavor::Posix),
             vec![
                 "--header".to_string(),
                 "'If-Match: \"e0023aa4e\"'".to_string()
             ]
         );
+        assert_eq!(
+            Header::new("If-Match", "\"e0023aa4e\"").curl_args(ShellFlavor::PowerShell),
+            vec![
+                "--header".to_string(),
+                "\"If-Match: `\"e0023aa4e`\"\"".to_string()
+            ]
+        );
+        assert_eq!(
+            Header::new("If-Match", "\"e0023aa4e\"").curl_args(ShellFlavor::Cmd),
+            vec![
+                "--header".to_string(),
+                "\"If-Match: \\\"e0023aa4e\\\"\"".to_string()
+            ]
+        );
     }
 
     #[test]
@@ -397,7 +806,7 @@ pub mod tests {
         assert_eq!(
             query_http_request().curl_args(context_dir),
             vec![
-                "'http://localhost:8000/querystring-params?param1=value1&param2=a%20b'".to_string()
+                "'http://localhost:8000/querystring-params?param1=value1&param2=a+b'".to_string()
             ]
         );
         assert_eq!(
@@ -408,7 +817,7 @@ pub mod tests {
                 "--data".to_string(),
                 "'param1=value1'".to_string(),
                 "--data".to_string(),
-                "'param2=a%20b'".to_string(),
+                "'param2=a+b'".to_string(),
                 "'http://localhost/form-params'".to_string(),
             ]
         );
@@ -434,6 +843,58 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn requests_curl_args_powershell() {
+        let context_dir = &ContextDir::default();
+        assert_eq!(
+            json_request().curl_args_for(context_dir, ShellFlavor::PowerShell),
+            vec![
+                "--header".to_string(),
+                "\"content-type: application/vnd.api+json\"".to_string(),
+                "--data".to_string(),
+                "\"{`\"foo`\":`\"bar`\"}\"".to_string(),
+                "\"http://localhost/json\"".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_http_raw() {
+        let context_dir = &ContextDir::default();
+        let raw = json_request().http_raw(context_dir);
+        assert_eq!(
+            String::from_utf8(raw).unwrap(),
+            "POST /json HTTP/1.1\r\n\
+             Host: localhost\r\n\
+             content-type: application/vnd.api+json\r\n\
+             Content-Length: 13\r\n\
+             \r\n\
+             {\"foo\":\"bar\"}"
+        );
+    }
+
+    #[test]
+    fn transport_curl_args() {
+        let context_dir = &ContextDir::default();
+        let req = RequestSpec {
+            method: Method("GET".to_string()),
+            url: Url::from_str("http://localhost:8000/").unwrap(),
+            http_version: RequestedHttpVersion::Http2,
+            compressed: true,
+            ssl_min_version: Some(SslVersion::Tls1_3),
+            ..Default::default()
+        };
+        assert_eq!(
+            req.curl_args(context_dir),
+            vec![
+                "--http2".to_string(),
+                "--compressed".to_string(),
+                "--tlsv1.3".to_string(),
+                "'http://localhost:8000/'".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn post_data_curl_args() {
         let context_dir = &ContextDir::default();
@@ -479,30 +940,49 @@ pub mod tests {
         let file_root = Path::new("/tmp");
         let context_dir = ContextDir::new(current_dir, file_root);
         assert_eq!(
-            Body::Text("hello".to_string()).curl_arg(&context_dir),
+            Body::Text("hello".to_string()).curl_arg(&context_dir, ShellFlavor::Posix),
             "'hello'".to_string()
         );
 
         if cfg!(unix) {
             assert_eq!(
-                Body::File(vec![], "filename".to_string()).curl_arg(&context_dir),
+                Body::File(vec![], "filename".to_string())
+                    .curl_arg(&context_dir, ShellFlavor::Posix),
                 "'@/tmp/filename'".to_string()
             );
         }
 
         assert_eq!(
-            Body::Binary(vec![1, 2, 3]).curl_arg(&context_dir),
+            Body::Binary(vec![1, 2, 3]).curl_arg(&context_dir, ShellFlavor::Posix),
             "$'\\x01\\x02\\x03'".to_string()
         );
     }
 
     #[test]
     fn test_encode_shell_string() {
-        assert_eq!(encode_shell_string("hello"), "'hello'");
-        assert_eq!(encode_shell_string("\\n"), "'\\n'");
-        assert_eq!(encode_shell_string("'"), "$'\\''");
-        assert_eq!(encode_shell_string("\\'"), "$'\\\\\\''");
-        assert_eq!(encode_shell_string("\n"), "$'\\n'");
+        assert_eq!(encode_shell_string("hello", ShellFlavor::Posix), "'hello'");
+        assert_eq!(encode_shell_string("\\n", ShellFlavor::Posix), "'\\n'");
+        assert_eq!(encode_shell_string("'", ShellFlavor::Posix), "$'\\''");
+        assert_eq!(encode_shell_string("\\'", ShellFlavor::Posix), "$'\\\\\\''");
+        assert_eq!(encode_shell_string("\n", ShellFlavor::Posix), "$'\\n'");
+
+        assert_eq!(
+            encode_shell_string("hello", ShellFlavor::PowerShell),
+            "\"hello\""
+        );
+        assert_eq!(
+            encode_shell_string("a\"b", ShellFlavor::PowerShell),
+            "\"a`\"b\""
+        );
+        assert_eq!(encode_shell_string("\n", ShellFlavor::PowerShell), "\"`n\"");
+
+        assert_eq!(encode_shell_string("hello", ShellFlavor::Cmd), "\"hello\"");
+        assert_eq!(encode_shell_string("a\"b", ShellFlavor::Cmd), "\"a\\\"b\"");
+        // `&` is literal inside double quotes, so it must be emitted verbatim —
+        // a leading caret would reach curl as part of the value.
+        assert_eq!(encode_shell_string("a&b", ShellFlavor::Cmd), "\"a&b\"");
+        assert_eq!(encode_shell_string("%PATH%", ShellFlavor::Cmd), "\"%%PATH%%\"");
+        assert_eq!(encode_shell_string("a\nb", ShellFlavor::Cmd), "\"a\\nb\"");
     }
 
     #[test]